@@ -0,0 +1,1108 @@
+use std::fmt;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use num::{Float, Zero, One, Num, NumCast, ToPrimitive, FromPrimitive};
+
+#[cfg(test)]
+use std::f32::consts;
+#[cfg(test)]
+use std::f32;
+
+/// Dual Numbers
+#[derive(Copy, Clone, PartialEq, PartialOrd, Hash, Debug)]
+pub struct Dual<F> {
+    real: F,
+    dual: F,
+}
+
+pub type Dual32 = Dual<f32>;
+pub type Dual64 = Dual<f64>;
+
+impl<F: Copy + Float> Dual<F> {
+    pub fn new(real: F, deriv: F) -> Dual<F> {
+        Dual {
+            real: real,
+            dual: deriv,
+        }
+    }
+
+    pub fn real(self) -> F {
+        self.real
+    }
+
+    pub fn derivative(self) -> F {
+        self.dual
+    }
+
+    /// A constant: a value with no dependence on any variable, so its
+    /// derivative is zero. A bare scalar mixed into a `Dual` expression
+    /// (e.g. `x + 2.0`) is treated the same way.
+    pub fn constant(real: F) -> Dual<F> {
+        Dual::new(real, F::zero())
+    }
+
+    /// An independent variable seeded at `real`: its derivative with
+    /// respect to itself is one.
+    pub fn variable(real: F) -> Dual<F> {
+        Dual::new(real, F::one())
+    }
+}
+
+impl<F: Copy + Float> Zero for Dual<F> {
+    fn zero() -> Dual<F> {
+        Dual::new(F::zero(), F::zero())
+    }
+
+    fn is_zero(&self) -> bool {
+        self.real.is_zero() && self.dual.is_zero()
+    }
+}
+
+impl<F: Copy + Float> One for Dual<F> {
+    // The multiplicative identity has a zero dual part: the derivative of a
+    // constant is zero, so `one()` must not be an independent variable.
+    fn one() -> Dual<F> {
+        Dual::new(F::one(), F::zero())
+    }
+}
+
+impl<F: Copy + Float> Add<Dual<F>> for Dual<F> {
+    type Output = Dual<F>;
+
+    fn add(self, other: Dual<F>) -> Dual<F> {
+        Dual::new(self.real + other.real, self.dual + other.dual)
+    }
+}
+
+#[test]
+fn test_add_struct() {
+    let x = Dual::new(3.0, 4.0);
+    let y = Dual::new(2.0, 3.0);
+
+    let z = x + y;
+    assert!(z.real == 5.0);
+    assert!(z.dual == 7.0);
+}
+
+impl<F: Copy + Float> Sub<Dual<F>> for Dual<F> {
+    type Output = Dual<F>;
+
+    fn sub(self, other: Dual<F>) -> Dual<F> {
+        Dual::new(self.real - other.real, self.dual - other.dual)
+    }
+}
+
+#[test]
+fn test_sub() {
+    let x = Dual::new(3.0, 4.0);
+    let y = Dual::new(2.0, 12.0);
+
+    let z = x - y;
+    assert!(z.real == 1.0);
+    assert!(z.dual == -8.0);
+}
+
+impl<F: Copy + Float + Neg<Output = F>> Neg for Dual<F> {
+    type Output = Dual<F>;
+
+    fn neg(self) -> Dual<F> {
+        Dual::new(-self.real, -self.dual)
+    }
+}
+
+#[test]
+fn test_neg_plus() {
+    let x = Dual::new(3.0, 4.0);
+    let y = Dual::new(2.0, 12.0);
+
+    let z1 = x - y;
+    let z2 = x + (-y);
+    assert!(z1 == z2);
+}
+
+#[test]
+fn test_neg_zero() {
+    let x = Dual::new(3.0, 4.0);
+    let zero: Dual32 = Zero::zero();
+
+    let z1 = -x;
+    let z2 = zero - x;
+    assert!(z1 == z2);
+}
+
+impl<F: Copy + Float> Mul<Dual<F>> for Dual<F> {
+    type Output = Dual<F>;
+
+    fn mul(self, other: Dual<F>) -> Dual<F> {
+        Dual::new(self.real * other.real,
+                  (self.real * other.dual) + (self.dual * other.real))
+    }
+}
+
+#[test]
+fn test_mul() {
+    let x = Dual::new(3.0, 4.0);
+    let y = Dual::new(1.0, 2.0);
+
+    let z = x * y;
+    assert!(z.real == 3.0);
+    assert!(z.dual == 10.0);
+}
+
+impl<F: Copy + Float> Div<Dual<F>> for Dual<F> {
+    type Output = Dual<F>;
+
+    fn div(self, other: Dual<F>) -> Dual<F> {
+        Dual::new(self.real / other.real,
+                  ((self.dual * other.real) - (self.real * other.dual)) / (other.real * other.real))
+    }
+}
+
+#[test]
+fn test_div() {
+    let x = Dual::new(3.0, 4.0);
+    let y = Dual::new(1.0, 2.0);
+
+    let z = x / y;
+    assert!(z.real == 3.0);
+    assert!(z.dual == -2.0);
+}
+
+impl<F: Copy + Float> ::std::ops::Rem<Dual<F>> for Dual<F> {
+    type Output = Dual<F>;
+
+    // x % y == x - y * trunc(x / y), so d(x % y) == dx - trunc(x / y) * dy.
+    fn rem(self, other: Dual<F>) -> Dual<F> {
+        let quotient = (self.real / other.real).trunc();
+        Dual::new(self.real % other.real, self.dual - quotient * other.dual)
+    }
+}
+
+#[test]
+fn test_rem() {
+    let x = Dual::new(7.0, 1.0);
+    let y = Dual::new(3.0, 0.0);
+
+    let z = x % y;
+    assert!(z.real == 1.0);
+    assert!(z.dual == 1.0);
+}
+
+impl<F: Copy + Float> Num for Dual<F> {
+    type FromStrRadixErr = F::FromStrRadixErr;
+
+    fn from_str_radix(str: &str, radix: u32) -> Result<Dual<F>, Self::FromStrRadixErr> {
+        F::from_str_radix(str, radix).map(|real| Dual::new(real, F::zero()))
+    }
+}
+
+impl<F: Copy + Float> NumCast for Dual<F> {
+    fn from<T: ToPrimitive>(n: T) -> Option<Dual<F>> {
+        F::from(n).map(|real| Dual::new(real, F::zero()))
+    }
+}
+
+impl<F: Copy + Float> ToPrimitive for Dual<F> {
+    fn to_i64(&self) -> Option<i64> {
+        self.real.to_i64()
+    }
+
+    fn to_u64(&self) -> Option<u64> {
+        self.real.to_u64()
+    }
+
+    fn to_f64(&self) -> Option<f64> {
+        self.real.to_f64()
+    }
+}
+
+impl<F: Copy + Float> FromPrimitive for Dual<F> {
+    fn from_i64(n: i64) -> Option<Dual<F>> {
+        NumCast::from(n).map(|real| Dual::new(real, F::zero()))
+    }
+
+    fn from_u64(n: u64) -> Option<Dual<F>> {
+        NumCast::from(n).map(|real| Dual::new(real, F::zero()))
+    }
+
+    fn from_f64(n: f64) -> Option<Dual<F>> {
+        NumCast::from(n).map(|real| Dual::new(real, F::zero()))
+    }
+}
+
+impl<F: Copy + Float> Dual<F> {
+    pub fn sin(self) -> Dual<F> {
+        Dual::new(self.real.sin(), self.dual * self.real.cos())
+    }
+
+    pub fn cos(self) -> Dual<F> {
+        Dual::new(self.real.cos(), (-F::one()) * self.dual * self.real.sin())
+    }
+
+    pub fn tan(self) -> Dual<F> {
+        self.sin() / self.cos()
+    }
+
+    pub fn exp(self) -> Dual<F> {
+        Dual::new(self.real.exp(), self.dual * self.real.exp())
+    }
+
+    pub fn ln(self) -> Dual<F> {
+        Dual::new(self.real.ln(), self.dual / self.real)
+    }
+
+    pub fn sqrt(self) -> Dual<F> {
+        let two = F::one() + F::one();
+        Dual::new(self.real.sqrt(), self.dual / (two * self.real.sqrt()))
+    }
+
+    pub fn abs(self) -> Dual<F> {
+        Dual::new(self.real.abs(), self.dual * self.real.signum())
+    }
+
+    pub fn powi(self, n: i32) -> Dual<F> {
+        let factor = F::from(n).unwrap() * self.real.powi(n - 1);
+        Dual::new(self.real.powi(n), self.dual * factor)
+    }
+
+    /// Raises `self` to a constant scalar power `n`: `d/dx x^n = n*x^(n-1)`.
+    /// For a power that is itself a `Dual` (and so may carry its own
+    /// derivative), use `pow`.
+    pub fn powf(self, n: F) -> Dual<F> {
+        let factor = n * self.real.powf(n - F::one());
+        Dual::new(self.real.powf(n), self.dual * factor)
+    }
+
+    /// Raises `self` to a `Dual` power `n`, propagating derivatives through
+    /// both the base and the exponent: `d/dx x^y = y*x^(y-1)*dx +
+    /// x^y*ln(x)*dy`.
+    pub fn pow(self, n: Dual<F>) -> Dual<F> {
+        let real = self.real.powf(n.real);
+        let dual = n.real * self.real.powf(n.real - F::one()) * self.dual +
+                   real * self.real.ln() * n.dual;
+        Dual::new(real, dual)
+    }
+
+    pub fn exp2(self) -> Dual<F> {
+        let two = F::one() + F::one();
+        Dual::new(self.real.exp2(), self.dual * self.real.exp2() * two.ln())
+    }
+
+    /// Logarithm of `self` to a constant scalar `base`. For a base that is
+    /// itself a `Dual`, use `Float::log`.
+    pub fn log(self, base: F) -> Dual<F> {
+        Dual::new(self.real.log(base), self.dual / (self.real * base.ln()))
+    }
+
+    pub fn log2(self) -> Dual<F> {
+        let two = F::one() + F::one();
+        Dual::new(self.real.log2(), self.dual / (self.real * two.ln()))
+    }
+
+    pub fn log10(self) -> Dual<F> {
+        let ten = F::from(10).unwrap();
+        Dual::new(self.real.log10(), self.dual / (self.real * ten.ln()))
+    }
+
+    pub fn cbrt(self) -> Dual<F> {
+        let root = self.real.cbrt();
+        let three = F::one() + F::one() + F::one();
+        Dual::new(root, self.dual / (three * root * root))
+    }
+
+    pub fn hypot(self, other: Dual<F>) -> Dual<F> {
+        let real = self.real.hypot(other.real);
+        let dual = (self.real * self.dual + other.real * other.dual) / real;
+        Dual::new(real, dual)
+    }
+
+    pub fn asin(self) -> Dual<F> {
+        let denom = (F::one() - self.real * self.real).sqrt();
+        Dual::new(self.real.asin(), self.dual / denom)
+    }
+
+    pub fn acos(self) -> Dual<F> {
+        let denom = (F::one() - self.real * self.real).sqrt();
+        Dual::new(self.real.acos(), -self.dual / denom)
+    }
+
+    pub fn atan(self) -> Dual<F> {
+        Dual::new(self.real.atan(), self.dual / (F::one() + self.real * self.real))
+    }
+
+    pub fn atan2(self, other: Dual<F>) -> Dual<F> {
+        let denom = self.real * self.real + other.real * other.real;
+        let real = self.real.atan2(other.real);
+        let dual = (other.real * self.dual - self.real * other.dual) / denom;
+        Dual::new(real, dual)
+    }
+
+    pub fn sinh(self) -> Dual<F> {
+        Dual::new(self.real.sinh(), self.dual * self.real.cosh())
+    }
+
+    pub fn cosh(self) -> Dual<F> {
+        Dual::new(self.real.cosh(), self.dual * self.real.sinh())
+    }
+
+    pub fn tanh(self) -> Dual<F> {
+        let tanh = self.real.tanh();
+        Dual::new(tanh, self.dual * (F::one() - tanh * tanh))
+    }
+}
+
+#[test]
+fn test_abs() {
+    let x = Dual::new(-3.0, 1.0);
+    let y = x.abs();
+
+    assert!(y.real == 3.0);
+    assert!(y.dual == -1.0);
+}
+
+#[test]
+fn test_powi() {
+    let x = Dual::new(2.0, 1.0);
+    let y = x.powi(3);
+
+    assert!(y.real == 8.0);
+    assert!(y.dual == 12.0);
+}
+
+#[test]
+fn test_powf() {
+    let x = Dual::new(4.0, 1.0);
+    let y = x.powf(0.5);
+
+    assert!(diff(y.real, 2.0) < f32::EPSILON);
+    assert!(diff(y.dual, 0.25) < f32::EPSILON);
+}
+
+#[test]
+fn test_pow() {
+    // x^y at x=e, y=2 (constant exponent): matches powf when y.dual == 0.
+    let x = Dual::new(consts::E, 1.0);
+    let y = Dual::new(2.0, 0.0);
+    let z = x.pow(y);
+
+    assert!(diff(z.real, consts::E * consts::E) < 1e-3);
+    assert!(diff(z.dual, 2.0 * consts::E) < 1e-3);
+}
+
+#[test]
+fn test_cbrt() {
+    let x = Dual::new(8.0, 1.0);
+    let y = x.cbrt();
+
+    assert!(diff(y.real, 2.0) < f32::EPSILON);
+    assert!(diff(y.dual, 1.0 / 12.0) < f32::EPSILON);
+}
+
+#[test]
+fn test_hypot() {
+    let x = Dual::new(3.0, 1.0);
+    let y = Dual::new(4.0, 0.0);
+    let z = x.hypot(y);
+
+    assert!(diff(z.real, 5.0) < f32::EPSILON);
+    assert!(diff(z.dual, 0.6) < f32::EPSILON);
+}
+
+#[test]
+fn test_asin() {
+    let x: Dual32 = Dual::variable(0.0);
+    let y = x.asin();
+
+    assert!(diff(y.real, 0.0) < f32::EPSILON);
+    assert!(diff(y.dual, 1.0) < f32::EPSILON);
+}
+
+#[test]
+fn test_acos() {
+    let x: Dual32 = Dual::variable(0.0);
+    let y = x.acos();
+
+    assert!(diff(y.real, consts::FRAC_PI_2) < f32::EPSILON);
+    assert!(diff(y.dual, -1.0) < f32::EPSILON);
+}
+
+#[test]
+fn test_atan() {
+    let x: Dual32 = Dual::variable(0.0);
+    let y = x.atan();
+
+    assert!(diff(y.real, 0.0) < f32::EPSILON);
+    assert!(diff(y.dual, 1.0) < f32::EPSILON);
+}
+
+#[test]
+fn test_atan2() {
+    let x = Dual::new(0.0, 1.0);
+    let y = Dual::new(1.0, 0.0);
+    let z = x.atan2(y);
+
+    assert!(diff(z.real, 0.0) < f32::EPSILON);
+    assert!(diff(z.dual, 1.0) < f32::EPSILON);
+}
+
+#[test]
+fn test_sinh() {
+    let x: Dual32 = Dual::variable(0.0);
+    let y = x.sinh();
+
+    assert!(diff(y.real, 0.0) < f32::EPSILON);
+    assert!(diff(y.dual, 1.0) < f32::EPSILON);
+}
+
+#[test]
+fn test_cosh() {
+    let x: Dual32 = Dual::variable(0.0);
+    let y = x.cosh();
+
+    assert!(diff(y.real, 1.0) < f32::EPSILON);
+    assert!(diff(y.dual, 0.0) < f32::EPSILON);
+}
+
+#[test]
+fn test_tanh() {
+    let x: Dual32 = Dual::variable(0.0);
+    let y = x.tanh();
+
+    assert!(diff(y.real, 0.0) < f32::EPSILON);
+    assert!(diff(y.dual, 1.0) < f32::EPSILON);
+}
+
+#[test]
+fn test_exp2() {
+    let x: Dual32 = Dual::variable(1.0);
+    let y = x.exp2();
+
+    assert!(diff(y.real, 2.0) < f32::EPSILON);
+    assert!(diff(y.dual, 2.0 * consts::LN_2) < f32::EPSILON);
+}
+
+#[test]
+fn test_log2() {
+    let x: Dual32 = Dual::variable(1.0);
+    let y = x.log2();
+
+    assert!(diff(y.real, 0.0) < f32::EPSILON);
+    assert!(diff(y.dual, 1.0 / consts::LN_2) < f32::EPSILON);
+}
+
+#[test]
+fn test_log10() {
+    let x: Dual32 = Dual::variable(1.0);
+    let y = x.log10();
+
+    assert!(diff(y.real, 0.0) < f32::EPSILON);
+    assert!(diff(y.dual, 1.0 / consts::LN_10) < f32::EPSILON);
+}
+
+#[test]
+fn test_log_base() {
+    let x: Dual32 = Dual::variable(8.0);
+    let y = x.log(2.0);
+
+    assert!(diff(y.real, 3.0) < f32::EPSILON);
+    assert!(diff(y.dual, 1.0 / (8.0 * consts::LN_2)) < f32::EPSILON);
+}
+
+// `Float` lifts every method to propagate derivatives via the chain rule, so
+// `Dual<F>` can be dropped into generic code written against `F: Float`
+// (Newton solvers, `nalgebra`, polynomial evaluators, and the like).
+impl<F: Copy + Float> Float for Dual<F> {
+    fn nan() -> Dual<F> {
+        Dual::new(F::nan(), F::zero())
+    }
+
+    fn infinity() -> Dual<F> {
+        Dual::new(F::infinity(), F::zero())
+    }
+
+    fn neg_infinity() -> Dual<F> {
+        Dual::new(F::neg_infinity(), F::zero())
+    }
+
+    fn neg_zero() -> Dual<F> {
+        Dual::new(F::neg_zero(), F::zero())
+    }
+
+    fn min_value() -> Dual<F> {
+        Dual::new(F::min_value(), F::zero())
+    }
+
+    fn min_positive_value() -> Dual<F> {
+        Dual::new(F::min_positive_value(), F::zero())
+    }
+
+    fn max_value() -> Dual<F> {
+        Dual::new(F::max_value(), F::zero())
+    }
+
+    fn is_nan(self) -> bool {
+        self.real.is_nan() || self.dual.is_nan()
+    }
+
+    fn is_infinite(self) -> bool {
+        self.real.is_infinite()
+    }
+
+    fn is_finite(self) -> bool {
+        self.real.is_finite()
+    }
+
+    fn is_normal(self) -> bool {
+        self.real.is_normal()
+    }
+
+    fn classify(self) -> ::std::num::FpCategory {
+        self.real.classify()
+    }
+
+    // `floor`/`ceil`/`round`/`trunc` are piecewise constant, so their
+    // derivative is zero everywhere they're differentiable.
+    fn floor(self) -> Dual<F> {
+        Dual::new(self.real.floor(), F::zero())
+    }
+
+    fn ceil(self) -> Dual<F> {
+        Dual::new(self.real.ceil(), F::zero())
+    }
+
+    fn round(self) -> Dual<F> {
+        Dual::new(self.real.round(), F::zero())
+    }
+
+    fn trunc(self) -> Dual<F> {
+        Dual::new(self.real.trunc(), F::zero())
+    }
+
+    fn fract(self) -> Dual<F> {
+        Dual::new(self.real.fract(), self.dual)
+    }
+
+    fn abs(self) -> Dual<F> {
+        Dual::abs(self)
+    }
+
+    fn signum(self) -> Dual<F> {
+        Dual::new(self.real.signum(), F::zero())
+    }
+
+    fn is_sign_positive(self) -> bool {
+        self.real.is_sign_positive()
+    }
+
+    fn is_sign_negative(self) -> bool {
+        self.real.is_sign_negative()
+    }
+
+    fn mul_add(self, a: Dual<F>, b: Dual<F>) -> Dual<F> {
+        self * a + b
+    }
+
+    fn recip(self) -> Dual<F> {
+        Dual::new(self.real.recip(), -self.dual / (self.real * self.real))
+    }
+
+    fn powi(self, n: i32) -> Dual<F> {
+        Dual::powi(self, n)
+    }
+
+    // `Float::powf` takes a `Self` exponent, so it always propagates
+    // derivatives through both arguments; that's exactly `Dual::pow`.
+    fn powf(self, n: Dual<F>) -> Dual<F> {
+        self.pow(n)
+    }
+
+    fn sqrt(self) -> Dual<F> {
+        Dual::sqrt(self)
+    }
+
+    fn exp(self) -> Dual<F> {
+        Dual::exp(self)
+    }
+
+    fn exp2(self) -> Dual<F> {
+        Dual::exp2(self)
+    }
+
+    fn ln(self) -> Dual<F> {
+        Dual::ln(self)
+    }
+
+    // `Float::log` takes a `Self` base, so both the value and the base
+    // contribute to the result's dual part: log(x, base) = ln(x)/ln(base).
+    fn log(self, base: Dual<F>) -> Dual<F> {
+        let ln_base = base.real.ln();
+        let real = self.real.log(base.real);
+        let dual = self.dual / (self.real * ln_base) -
+                   self.real.ln() * base.dual / (base.real * ln_base * ln_base);
+        Dual::new(real, dual)
+    }
+
+    fn log2(self) -> Dual<F> {
+        Dual::log2(self)
+    }
+
+    fn log10(self) -> Dual<F> {
+        Dual::log10(self)
+    }
+
+    fn to_degrees(self) -> Dual<F> {
+        Dual::new(self.real.to_degrees(), self.dual.to_degrees())
+    }
+
+    fn to_radians(self) -> Dual<F> {
+        Dual::new(self.real.to_radians(), self.dual.to_radians())
+    }
+
+    // Forward-mode AD has no natural subgradient at a tie, so - like
+    // `f64::max`/`f64::min` - the whole dual of the winning real part wins.
+    fn max(self, other: Dual<F>) -> Dual<F> {
+        if self.real >= other.real { self } else { other }
+    }
+
+    fn min(self, other: Dual<F>) -> Dual<F> {
+        if self.real <= other.real { self } else { other }
+    }
+
+    fn abs_sub(self, other: Dual<F>) -> Dual<F> {
+        if self.real > other.real { self - other } else { Dual::new(F::zero(), F::zero()) }
+    }
+
+    fn cbrt(self) -> Dual<F> {
+        Dual::cbrt(self)
+    }
+
+    fn hypot(self, other: Dual<F>) -> Dual<F> {
+        Dual::hypot(self, other)
+    }
+
+    fn sin(self) -> Dual<F> {
+        Dual::sin(self)
+    }
+
+    fn cos(self) -> Dual<F> {
+        Dual::cos(self)
+    }
+
+    fn tan(self) -> Dual<F> {
+        Dual::tan(self)
+    }
+
+    fn asin(self) -> Dual<F> {
+        Dual::asin(self)
+    }
+
+    fn acos(self) -> Dual<F> {
+        Dual::acos(self)
+    }
+
+    fn atan(self) -> Dual<F> {
+        Dual::atan(self)
+    }
+
+    fn atan2(self, other: Dual<F>) -> Dual<F> {
+        Dual::atan2(self, other)
+    }
+
+    fn sin_cos(self) -> (Dual<F>, Dual<F>) {
+        (self.sin(), self.cos())
+    }
+
+    fn exp_m1(self) -> Dual<F> {
+        Dual::new(self.real.exp_m1(), self.dual * self.real.exp())
+    }
+
+    fn ln_1p(self) -> Dual<F> {
+        Dual::new(self.real.ln_1p(), self.dual / (F::one() + self.real))
+    }
+
+    fn sinh(self) -> Dual<F> {
+        Dual::sinh(self)
+    }
+
+    fn cosh(self) -> Dual<F> {
+        Dual::cosh(self)
+    }
+
+    fn tanh(self) -> Dual<F> {
+        Dual::tanh(self)
+    }
+
+    fn asinh(self) -> Dual<F> {
+        let denom = (self.real * self.real + F::one()).sqrt();
+        Dual::new(self.real.asinh(), self.dual / denom)
+    }
+
+    fn acosh(self) -> Dual<F> {
+        let denom = (self.real * self.real - F::one()).sqrt();
+        Dual::new(self.real.acosh(), self.dual / denom)
+    }
+
+    fn atanh(self) -> Dual<F> {
+        Dual::new(self.real.atanh(), self.dual / (F::one() - self.real * self.real))
+    }
+
+    fn integer_decode(self) -> (u64, i16, i8) {
+        self.real.integer_decode()
+    }
+}
+
+#[test]
+fn test_float_one_is_constant() {
+    let one: Dual32 = One::one();
+    assert!(one.real == 1.0);
+    assert!(one.dual == 0.0);
+}
+
+#[test]
+fn test_float_powi() {
+    let x = Dual::new(2.0f32, 1.0);
+    let y = Float::powi(x, 3);
+
+    assert!(y.real == 8.0);
+    assert!(y.dual == 12.0);
+}
+
+#[test]
+fn test_float_recip() {
+    let x = Dual::new(2.0f32, 1.0);
+    let y = Float::recip(x);
+
+    assert!(diff(y.real, 0.5) < f32::EPSILON);
+    assert!(diff(y.dual, -0.25) < f32::EPSILON);
+}
+
+#[test]
+fn test_float_asin() {
+    let x: Dual32 = Dual::variable(0.0);
+    let y = Float::asin(x);
+
+    assert!(diff(y.real, 0.0) < f32::EPSILON);
+    assert!(diff(y.dual, 1.0) < f32::EPSILON);
+}
+
+#[test]
+fn test_float_sinh() {
+    let x: Dual32 = Dual::variable(0.0);
+    let y = Float::sinh(x);
+
+    assert!(diff(y.real, 0.0) < f32::EPSILON);
+    assert!(diff(y.dual, 1.0) < f32::EPSILON);
+}
+
+#[test]
+fn test_float_hypot() {
+    let x = Dual::new(3.0f32, 1.0);
+    let y = Dual::new(4.0f32, 0.0);
+    let z = Float::hypot(x, y);
+
+    assert!(diff(z.real, 5.0) < f32::EPSILON);
+    assert!(diff(z.dual, 0.6) < f32::EPSILON);
+}
+
+impl<F: Copy + Float> From<F> for Dual<F> {
+    fn from(real: F) -> Dual<F> {
+        Dual::constant(real)
+    }
+}
+
+/// Seeds a variable at `x`, evaluates `f`, and returns its derivative: the
+/// ergonomic entry point for differentiating a single-argument function.
+pub fn differentiate<F, Func>(x: F, f: Func) -> F
+    where F: Copy + Float,
+          Func: Fn(Dual<F>) -> Dual<F>
+{
+    f(Dual::variable(x)).derivative()
+}
+
+#[test]
+fn test_differentiate() {
+    let d = differentiate(2.0, |x| x * x * x);
+    assert!(diff(d, 12.0) < f32::EPSILON);
+}
+
+#[test]
+fn test_constant_from_forwards_to_constant() {
+    let x: Dual32 = From::from(3.0);
+    assert!(x.real == 3.0);
+    assert!(x.dual == 0.0);
+}
+
+// A bare scalar mixed into a `Dual` expression is a constant: its
+// derivative is zero, so it contributes nothing to the chain rule.
+impl<F: Copy + Float> Add<F> for Dual<F> {
+    type Output = Dual<F>;
+
+    fn add(self, other: F) -> Dual<F> {
+        Dual::new(self.real + other, self.dual)
+    }
+}
+
+impl<F: Copy + Float> Sub<F> for Dual<F> {
+    type Output = Dual<F>;
+
+    fn sub(self, other: F) -> Dual<F> {
+        Dual::new(self.real - other, self.dual)
+    }
+}
+
+impl<F: Copy + Float> Mul<F> for Dual<F> {
+    type Output = Dual<F>;
+
+    fn mul(self, other: F) -> Dual<F> {
+        Dual::new(self.real * other, self.dual * other)
+    }
+}
+
+impl<F: Copy + Float> Div<F> for Dual<F> {
+    type Output = Dual<F>;
+
+    fn div(self, other: F) -> Dual<F> {
+        Dual::new(self.real / other, self.dual / other)
+    }
+}
+
+#[test]
+fn test_scalar_mixing() {
+    let x = Dual::variable(3.0);
+
+    assert!((x + 2.0).dual == 1.0);
+    assert!((x - 2.0).dual == 1.0);
+    assert!((x * 2.0).dual == 2.0);
+    assert!((x / 2.0).dual == 0.5);
+}
+
+// The reversed `F op Dual<F>` forms can't be generic over `F` (the orphan
+// rule forbids `impl<F> Add<Dual<F>> for F`), so they're spelled out for
+// each concrete float type the crate supports.
+macro_rules! impl_scalar_mixing {
+    ($f:ty) => {
+        impl Add<Dual<$f>> for $f {
+            type Output = Dual<$f>;
+
+            fn add(self, other: Dual<$f>) -> Dual<$f> {
+                Dual::new(self + other.real, other.dual)
+            }
+        }
+
+        impl Sub<Dual<$f>> for $f {
+            type Output = Dual<$f>;
+
+            fn sub(self, other: Dual<$f>) -> Dual<$f> {
+                Dual::new(self - other.real, -other.dual)
+            }
+        }
+
+        impl Mul<Dual<$f>> for $f {
+            type Output = Dual<$f>;
+
+            fn mul(self, other: Dual<$f>) -> Dual<$f> {
+                Dual::new(self * other.real, self * other.dual)
+            }
+        }
+
+        impl Div<Dual<$f>> for $f {
+            type Output = Dual<$f>;
+
+            fn div(self, other: Dual<$f>) -> Dual<$f> {
+                Dual::new(self / other.real,
+                          -self * other.dual / (other.real * other.real))
+            }
+        }
+    }
+}
+
+impl_scalar_mixing!(f32);
+impl_scalar_mixing!(f64);
+
+#[test]
+fn test_reversed_scalar_mixing() {
+    let x = Dual::variable(3.0f32);
+
+    assert!((2.0 + x).dual == 1.0);
+    assert!((2.0 - x).dual == -1.0);
+    assert!((2.0 * x).dual == 2.0);
+    assert!(diff((2.0 / x).dual, -2.0 / 9.0) < f32::EPSILON);
+}
+
+impl<F: Copy + Float> ::std::ops::AddAssign<Dual<F>> for Dual<F> {
+    fn add_assign(&mut self, other: Dual<F>) {
+        *self = *self + other;
+    }
+}
+
+impl<F: Copy + Float> ::std::ops::AddAssign<F> for Dual<F> {
+    fn add_assign(&mut self, other: F) {
+        *self = *self + other;
+    }
+}
+
+impl<F: Copy + Float> ::std::ops::SubAssign<Dual<F>> for Dual<F> {
+    fn sub_assign(&mut self, other: Dual<F>) {
+        *self = *self - other;
+    }
+}
+
+impl<F: Copy + Float> ::std::ops::SubAssign<F> for Dual<F> {
+    fn sub_assign(&mut self, other: F) {
+        *self = *self - other;
+    }
+}
+
+impl<F: Copy + Float> ::std::ops::MulAssign<Dual<F>> for Dual<F> {
+    fn mul_assign(&mut self, other: Dual<F>) {
+        *self = *self * other;
+    }
+}
+
+impl<F: Copy + Float> ::std::ops::MulAssign<F> for Dual<F> {
+    fn mul_assign(&mut self, other: F) {
+        *self = *self * other;
+    }
+}
+
+impl<F: Copy + Float> ::std::ops::DivAssign<Dual<F>> for Dual<F> {
+    fn div_assign(&mut self, other: Dual<F>) {
+        *self = *self / other;
+    }
+}
+
+impl<F: Copy + Float> ::std::ops::DivAssign<F> for Dual<F> {
+    fn div_assign(&mut self, other: F) {
+        *self = *self / other;
+    }
+}
+
+#[test]
+fn test_compound_assignment() {
+    let mut x = Dual::new(3.0, 1.0);
+    x += Dual::new(2.0, 1.0);
+    assert!(x == Dual::new(5.0, 2.0));
+
+    x -= 1.0;
+    assert!(x == Dual::new(4.0, 2.0));
+
+    x *= Dual::new(2.0, 0.0);
+    assert!(x == Dual::new(8.0, 4.0));
+
+    x /= 2.0;
+    assert!(x == Dual::new(4.0, 2.0));
+}
+
+impl<F: Copy + Float> ::std::iter::Sum for Dual<F> {
+    fn sum<I: Iterator<Item = Dual<F>>>(iter: I) -> Dual<F> {
+        iter.fold(Dual::zero(), Add::add)
+    }
+}
+
+impl<F: Copy + Float> ::std::iter::Product for Dual<F> {
+    fn product<I: Iterator<Item = Dual<F>>>(iter: I) -> Dual<F> {
+        iter.fold(Dual::one(), Mul::mul)
+    }
+}
+
+#[test]
+fn test_sum_and_product() {
+    let values = vec![Dual::new(1.0, 1.0), Dual::new(2.0, 0.0), Dual::new(3.0, 0.0)];
+
+    let sum: Dual<f64> = values.iter().cloned().sum();
+    assert!(sum == Dual::new(6.0, 1.0));
+
+    let product: Dual<f64> = values.into_iter().product();
+    assert!(product.real == 6.0);
+}
+
+impl<F> fmt::Display for Dual<F>
+    where F: Copy + Float + fmt::Display
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.real < Zero::zero() {
+            write!(f, "{}-{}ε", self.real, F::zero() - self.dual)
+        } else {
+            write!(f, "{}+{}ε", self.real, self.dual)
+        }
+    }
+}
+
+#[cfg(test)]
+fn diff(x: f32, y: f32) -> f32 {
+    (x - y).abs()
+}
+
+#[test]
+fn test_sin() {
+    let x: Dual32 = Dual::variable(0.0);
+    let y = x.sin();
+
+    let real_diff = diff(y.real, 0.0);
+    let dual_diff = diff(y.dual, 1.0);
+
+    assert!(real_diff < f32::EPSILON);
+    assert!(dual_diff < f32::EPSILON);
+}
+
+#[test]
+fn test_cos() {
+    let x: Dual32 = Dual::variable(consts::PI);
+    let y = x.cos();
+
+    let real_diff = diff(y.real, -1.0);
+    let dual_diff = diff(y.dual, 0.0);
+
+    assert!(real_diff < f32::EPSILON);
+    assert!(dual_diff < f32::EPSILON);
+}
+
+#[test]
+fn test_tan() {
+    let x: Dual32 = Dual::variable(0.0);
+    let y = x.tan();
+
+    let real_diff = diff(y.real, 0.0);
+    let dual_diff = diff(y.dual, 1.0);
+
+    assert!(real_diff < f32::EPSILON);
+    assert!(dual_diff < f32::EPSILON);
+}
+
+#[test]
+fn test_exp() {
+    let x: Dual32 = Dual::variable(1.0);
+    let y = x.exp();
+
+    let real_diff = diff(y.real, consts::E);
+    let dual_diff = diff(y.dual, consts::E);
+
+    assert!(real_diff < f32::EPSILON);
+    assert!(dual_diff < f32::EPSILON);
+}
+
+#[test]
+fn test_ln() {
+    let x: Dual32 = Dual::variable(1.0);
+    let y = x.ln();
+
+    let real_diff = diff(y.real, 0.0);
+    let dual_diff = diff(y.dual, 1.0);
+
+    assert!(real_diff < f32::EPSILON);
+    assert!(dual_diff < f32::EPSILON);
+}
+
+#[test]
+fn test_sqrt() {
+    let x: Dual32 = Dual::variable(4.0);
+    let y = x.sqrt();
+
+    println!("{}", y);
+
+    let real_diff = diff(y.real, 2.0);
+    let dual_diff = diff(y.dual, 0.25);
+
+    assert!(real_diff < f32::EPSILON);
+    assert!(dual_diff < f32::EPSILON);
+}
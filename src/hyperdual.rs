@@ -0,0 +1,262 @@
+use std::fmt;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use num::{Float, Zero, One};
+
+/// Hyperdual numbers: `real + eps1*e1 + eps2*e2 + eps1eps2*e1*e2`, with the
+/// nilpotent algebra `e1*e1 == e2*e2 == (e1*e2)*(e1*e2) == 0`. Where `Dual`
+/// carries a first derivative, `HyperDual` carries an exact second
+/// derivative in the `eps1eps2` component.
+#[derive(Copy, Clone, PartialEq, Hash, Debug)]
+pub struct HyperDual<F> {
+    real: F,
+    eps1: F,
+    eps2: F,
+    eps1eps2: F,
+}
+
+pub type HyperDual32 = HyperDual<f32>;
+pub type HyperDual64 = HyperDual<f64>;
+
+impl<F: Copy + Float> HyperDual<F> {
+    pub fn new(real: F, eps1: F, eps2: F, eps1eps2: F) -> HyperDual<F> {
+        HyperDual {
+            real: real,
+            eps1: eps1,
+            eps2: eps2,
+            eps1eps2: eps1eps2,
+        }
+    }
+
+    pub fn real(self) -> F {
+        self.real
+    }
+
+    pub fn derivative(self) -> F {
+        self.eps1
+    }
+
+    pub fn second_derivative(self) -> F {
+        self.eps1eps2
+    }
+
+    /// A constant: no dependence on any variable, so every epsilon
+    /// component is zero.
+    pub fn constant(real: F) -> HyperDual<F> {
+        HyperDual::new(real, F::zero(), F::zero(), F::zero())
+    }
+
+    /// An independent variable seeded at `real`, ready to take both a first
+    /// and second derivative through it: `eps1 == eps2 == 1`.
+    pub fn variable(real: F) -> HyperDual<F> {
+        HyperDual::new(real, F::one(), F::one(), F::zero())
+    }
+}
+
+/// Seeds a variable at `x`, evaluates `f`, and returns `f''(x)` read out of
+/// the `eps1eps2` component - no finite differencing required. The first
+/// derivative is not returned.
+pub fn second_derivative<F, Func>(f: Func, x: F) -> F
+    where F: Copy + Float,
+          Func: Fn(HyperDual<F>) -> HyperDual<F>
+{
+    f(HyperDual::variable(x)).second_derivative()
+}
+
+impl<F: Copy + Float> Zero for HyperDual<F> {
+    fn zero() -> HyperDual<F> {
+        HyperDual::new(F::zero(), F::zero(), F::zero(), F::zero())
+    }
+
+    fn is_zero(&self) -> bool {
+        self.real.is_zero() && self.eps1.is_zero() && self.eps2.is_zero() &&
+        self.eps1eps2.is_zero()
+    }
+}
+
+impl<F: Copy + Float> One for HyperDual<F> {
+    fn one() -> HyperDual<F> {
+        HyperDual::new(F::one(), F::zero(), F::zero(), F::zero())
+    }
+}
+
+impl<F: Copy + Float> Add<HyperDual<F>> for HyperDual<F> {
+    type Output = HyperDual<F>;
+
+    fn add(self, other: HyperDual<F>) -> HyperDual<F> {
+        HyperDual::new(self.real + other.real,
+                        self.eps1 + other.eps1,
+                        self.eps2 + other.eps2,
+                        self.eps1eps2 + other.eps1eps2)
+    }
+}
+
+#[test]
+fn test_add() {
+    let x = HyperDual::new(3.0, 1.0, 1.0, 0.0);
+    let y = HyperDual::new(2.0, 1.0, 1.0, 0.0);
+
+    let z = x + y;
+    assert!(z.real == 5.0);
+    assert!(z.eps1 == 2.0);
+    assert!(z.eps2 == 2.0);
+    assert!(z.eps1eps2 == 0.0);
+}
+
+impl<F: Copy + Float> Sub<HyperDual<F>> for HyperDual<F> {
+    type Output = HyperDual<F>;
+
+    fn sub(self, other: HyperDual<F>) -> HyperDual<F> {
+        HyperDual::new(self.real - other.real,
+                        self.eps1 - other.eps1,
+                        self.eps2 - other.eps2,
+                        self.eps1eps2 - other.eps1eps2)
+    }
+}
+
+#[test]
+fn test_sub() {
+    let x = HyperDual::new(3.0, 1.0, 1.0, 0.0);
+    let y = HyperDual::new(2.0, 1.0, 1.0, 0.0);
+
+    let z = x - y;
+    assert!(z.real == 1.0);
+    assert!(z.eps1 == 0.0);
+    assert!(z.eps2 == 0.0);
+    assert!(z.eps1eps2 == 0.0);
+}
+
+impl<F: Copy + Float + Neg<Output = F>> Neg for HyperDual<F> {
+    type Output = HyperDual<F>;
+
+    fn neg(self) -> HyperDual<F> {
+        HyperDual::new(-self.real, -self.eps1, -self.eps2, -self.eps1eps2)
+    }
+}
+
+impl<F: Copy + Float> Mul<HyperDual<F>> for HyperDual<F> {
+    type Output = HyperDual<F>;
+
+    fn mul(self, other: HyperDual<F>) -> HyperDual<F> {
+        HyperDual::new(self.real * other.real,
+                        self.real * other.eps1 + self.eps1 * other.real,
+                        self.real * other.eps2 + self.eps2 * other.real,
+                        self.real * other.eps1eps2 + self.eps1 * other.eps2 +
+                        self.eps2 * other.eps1 + self.eps1eps2 * other.real)
+    }
+}
+
+#[test]
+fn test_mul() {
+    let x = HyperDual::new(3.0, 1.0, 1.0, 0.0);
+    let y = HyperDual::new(2.0, 1.0, 1.0, 0.0);
+
+    let z = x * y;
+    assert!(z.real == 6.0);
+    assert!(z.eps1 == 5.0);
+    assert!(z.eps2 == 5.0);
+    assert!(z.eps1eps2 == 2.0);
+}
+
+impl<F: Copy + Float> Div<HyperDual<F>> for HyperDual<F> {
+    type Output = HyperDual<F>;
+
+    // Division is multiplication by the reciprocal, whose expansion follows
+    // the same nilpotent rules: 1/(a + h) == 1/a - h/a^2 + h^2/a^3, since
+    // h^3 == 0 while h^2 == 2*eps1*eps2*e1*e2 is not zero.
+    fn div(self, other: HyperDual<F>) -> HyperDual<F> {
+        let inv_real = other.real.recip();
+        let inv = HyperDual::new(inv_real,
+                                  -other.eps1 * inv_real * inv_real,
+                                  -other.eps2 * inv_real * inv_real,
+                                  (other.eps1 * other.eps2 * (inv_real * inv_real * inv_real) *
+                                   (F::one() + F::one())) -
+                                  other.eps1eps2 * inv_real * inv_real);
+        self * inv
+    }
+}
+
+#[test]
+fn test_div() {
+    let x = HyperDual::new(6.0, 1.0, 1.0, 0.0);
+    let y = HyperDual::new(2.0, 1.0, 1.0, 0.0);
+
+    let z = x / y;
+    assert!(z.real == 3.0);
+}
+
+impl<F: Copy + Float> HyperDual<F> {
+    pub fn sin(self) -> HyperDual<F> {
+        let (s, c) = (self.real.sin(), self.real.cos());
+        HyperDual::new(s,
+                        self.eps1 * c,
+                        self.eps2 * c,
+                        (-s) * self.eps1 * self.eps2 + c * self.eps1eps2)
+    }
+
+    pub fn cos(self) -> HyperDual<F> {
+        let (s, c) = (self.real.sin(), self.real.cos());
+        HyperDual::new(c,
+                        (-s) * self.eps1,
+                        (-s) * self.eps2,
+                        (-c) * self.eps1 * self.eps2 + (-s) * self.eps1eps2)
+    }
+
+    pub fn exp(self) -> HyperDual<F> {
+        let e = self.real.exp();
+        HyperDual::new(e, self.eps1 * e, self.eps2 * e, e * self.eps1 * self.eps2 + e * self.eps1eps2)
+    }
+
+    pub fn ln(self) -> HyperDual<F> {
+        let recip = self.real.recip();
+        HyperDual::new(self.real.ln(),
+                        self.eps1 * recip,
+                        self.eps2 * recip,
+                        (-recip * recip) * self.eps1 * self.eps2 + recip * self.eps1eps2)
+    }
+
+    pub fn sqrt(self) -> HyperDual<F> {
+        let two = F::one() + F::one();
+        let four = two + two;
+        let root = self.real.sqrt();
+        let d1 = (two * root).recip();
+        let d2 = -(four * root * self.real).recip();
+        HyperDual::new(root, self.eps1 * d1, self.eps2 * d1,
+                        d2 * self.eps1 * self.eps2 + d1 * self.eps1eps2)
+    }
+}
+
+#[test]
+fn test_sin() {
+    let x = HyperDual::new(0.0f32, 1.0, 1.0, 0.0);
+    let y = x.sin();
+
+    assert!((y.real - 0.0).abs() < f32::EPSILON);
+    assert!((y.eps1 - 1.0).abs() < f32::EPSILON);
+    assert!((y.eps1eps2 - 0.0).abs() < f32::EPSILON);
+}
+
+#[test]
+fn test_second_derivative_of_sin_at_zero() {
+    // f(x) = sin(x), f'(0) = 1, f''(0) = 0
+    let x = HyperDual::variable(0.0f64);
+    let y = x.sin();
+
+    assert!(y.derivative() == 1.0);
+    assert!(y.second_derivative().abs() < 1e-12);
+}
+
+#[test]
+fn test_second_derivative_helper() {
+    // f(x) = x^3 via repeated multiplication, f''(x) = 6x, f''(2) = 12
+    let d2 = second_derivative(|x| x * x * x, 2.0f64);
+    assert!((d2 - 12.0).abs() < 1e-9);
+}
+
+impl<F> fmt::Display for HyperDual<F>
+    where F: Copy + Float + fmt::Display
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}+{}ε1+{}ε2+{}ε1ε2", self.real, self.eps1, self.eps2, self.eps1eps2)
+    }
+}
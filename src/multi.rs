@@ -0,0 +1,238 @@
+use std::fmt;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use num::{Float, Zero, One};
+
+/// Vector-mode dual numbers: one evaluation of a function of `N` inputs
+/// yields the whole gradient simultaneously, rather than requiring `N`
+/// separate `Dual` evaluations.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct MultiDual<F, const N: usize> {
+    real: F,
+    dual: [F; N],
+}
+
+impl<F: Copy + Float, const N: usize> MultiDual<F, N> {
+    pub fn new(real: F, dual: [F; N]) -> MultiDual<F, N> {
+        MultiDual {
+            real: real,
+            dual: dual,
+        }
+    }
+
+    pub fn real(self) -> F {
+        self.real
+    }
+
+    pub fn derivative(self) -> [F; N] {
+        self.dual
+    }
+
+    /// An independent variable seeded at `value`: a unit vector in slot
+    /// `index` of the gradient.
+    pub fn variable(value: F, index: usize) -> MultiDual<F, N> {
+        let mut dual = [F::zero(); N];
+        dual[index] = F::one();
+        MultiDual::new(value, dual)
+    }
+
+    /// A constant: no dependence on any variable, so every gradient
+    /// component is zero.
+    pub fn constant(value: F) -> MultiDual<F, N> {
+        MultiDual::new(value, [F::zero(); N])
+    }
+
+    fn scale(self, factor: F) -> [F; N] {
+        ::std::array::from_fn(|i| self.dual[i] * factor)
+    }
+}
+
+/// Seeds all `N` variables at `x` and evaluates `f`'s gradient in a single
+/// forward pass.
+pub fn gradient<F, const N: usize, Func>(f: Func, x: &[F; N]) -> [F; N]
+    where F: Copy + Float,
+          Func: Fn([MultiDual<F, N>; N]) -> MultiDual<F, N>
+{
+    let vars = ::std::array::from_fn(|i| MultiDual::variable(x[i], i));
+    f(vars).derivative()
+}
+
+impl<F: Copy + Float, const N: usize> Zero for MultiDual<F, N> {
+    fn zero() -> MultiDual<F, N> {
+        MultiDual::new(F::zero(), [F::zero(); N])
+    }
+
+    fn is_zero(&self) -> bool {
+        self.real.is_zero() && self.dual.iter().all(|d| d.is_zero())
+    }
+}
+
+impl<F: Copy + Float, const N: usize> One for MultiDual<F, N> {
+    fn one() -> MultiDual<F, N> {
+        MultiDual::new(F::one(), [F::zero(); N])
+    }
+}
+
+impl<F: Copy + Float, const N: usize> Add<MultiDual<F, N>> for MultiDual<F, N> {
+    type Output = MultiDual<F, N>;
+
+    fn add(self, other: MultiDual<F, N>) -> MultiDual<F, N> {
+        let dual = ::std::array::from_fn(|i| self.dual[i] + other.dual[i]);
+        MultiDual::new(self.real + other.real, dual)
+    }
+}
+
+#[test]
+fn test_multi_add() {
+    let x = MultiDual::new(3.0, [1.0, 0.0]);
+    let y = MultiDual::new(2.0, [0.0, 1.0]);
+
+    let z = x + y;
+    assert!(z.real == 5.0);
+    assert!(z.dual == [1.0, 1.0]);
+}
+
+impl<F: Copy + Float, const N: usize> Sub<MultiDual<F, N>> for MultiDual<F, N> {
+    type Output = MultiDual<F, N>;
+
+    fn sub(self, other: MultiDual<F, N>) -> MultiDual<F, N> {
+        let dual = ::std::array::from_fn(|i| self.dual[i] - other.dual[i]);
+        MultiDual::new(self.real - other.real, dual)
+    }
+}
+
+#[test]
+fn test_multi_sub() {
+    let x = MultiDual::new(3.0, [1.0, 0.0]);
+    let y = MultiDual::new(2.0, [0.0, 1.0]);
+
+    let z = x - y;
+    assert!(z.real == 1.0);
+    assert!(z.dual == [1.0, -1.0]);
+}
+
+impl<F: Copy + Float + Neg<Output = F>, const N: usize> Neg for MultiDual<F, N> {
+    type Output = MultiDual<F, N>;
+
+    fn neg(self) -> MultiDual<F, N> {
+        let dual = ::std::array::from_fn(|i| -self.dual[i]);
+        MultiDual::new(-self.real, dual)
+    }
+}
+
+impl<F: Copy + Float, const N: usize> Mul<MultiDual<F, N>> for MultiDual<F, N> {
+    type Output = MultiDual<F, N>;
+
+    fn mul(self, other: MultiDual<F, N>) -> MultiDual<F, N> {
+        let dual = ::std::array::from_fn(|i| {
+            self.real * other.dual[i] + self.dual[i] * other.real
+        });
+        MultiDual::new(self.real * other.real, dual)
+    }
+}
+
+#[test]
+fn test_multi_mul() {
+    let x = MultiDual::new(3.0, [1.0, 0.0]);
+    let y = MultiDual::new(2.0, [0.0, 1.0]);
+
+    let z = x * y;
+    assert!(z.real == 6.0);
+    assert!(z.dual == [2.0, 3.0]);
+}
+
+impl<F: Copy + Float, const N: usize> Div<MultiDual<F, N>> for MultiDual<F, N> {
+    type Output = MultiDual<F, N>;
+
+    fn div(self, other: MultiDual<F, N>) -> MultiDual<F, N> {
+        let dual = ::std::array::from_fn(|i| {
+            (self.dual[i] * other.real - self.real * other.dual[i]) / (other.real * other.real)
+        });
+        MultiDual::new(self.real / other.real, dual)
+    }
+}
+
+#[test]
+fn test_multi_div() {
+    let x = MultiDual::new(6.0, [1.0, 0.0]);
+    let y = MultiDual::new(2.0, [0.0, 1.0]);
+
+    let z = x / y;
+    assert!(z.real == 3.0);
+    assert!(z.dual == [0.5, -1.5]);
+}
+
+impl<F: Copy + Float, const N: usize> MultiDual<F, N> {
+    pub fn sin(self) -> MultiDual<F, N> {
+        MultiDual::new(self.real.sin(), self.scale(self.real.cos()))
+    }
+
+    pub fn cos(self) -> MultiDual<F, N> {
+        MultiDual::new(self.real.cos(), self.scale(-self.real.sin()))
+    }
+
+    pub fn tan(self) -> MultiDual<F, N> {
+        self.sin() / self.cos()
+    }
+
+    pub fn exp(self) -> MultiDual<F, N> {
+        MultiDual::new(self.real.exp(), self.scale(self.real.exp()))
+    }
+
+    pub fn ln(self) -> MultiDual<F, N> {
+        MultiDual::new(self.real.ln(), self.scale(self.real.recip()))
+    }
+
+    pub fn sqrt(self) -> MultiDual<F, N> {
+        let two = F::one() + F::one();
+        let root = self.real.sqrt();
+        MultiDual::new(root, self.scale((two * root).recip()))
+    }
+}
+
+#[test]
+fn test_multi_sin() {
+    let x = MultiDual::new(0.0, [1.0, 0.0]);
+    let y = x.sin();
+
+    assert!(y.real == 0.0);
+    assert!(y.dual == [1.0, 0.0]);
+}
+
+#[test]
+fn test_gradient() {
+    // f(x, y) = x*x*y + y, grad = (2*x*y, x*x + 1)
+    let grad = gradient(|v| v[0] * v[0] * v[1] + v[1], &[3.0, 2.0]);
+
+    assert!(grad == [12.0, 10.0]);
+}
+
+#[test]
+fn test_variable_seeds_unit() {
+    let x: MultiDual<f64, 3> = MultiDual::variable(5.0, 1);
+
+    assert!(x.real == 5.0);
+    assert!(x.dual == [0.0, 1.0, 0.0]);
+}
+
+#[test]
+fn test_constant_has_zero_dual() {
+    let x: MultiDual<f64, 3> = MultiDual::constant(5.0);
+
+    assert!(x.dual == [0.0, 0.0, 0.0]);
+}
+
+impl<F, const N: usize> fmt::Display for MultiDual<F, N>
+    where F: Copy + Float + fmt::Display
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}+[", self.real)?;
+        for (i, d) in self.dual.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}ε{}", d, i)?;
+        }
+        write!(f, "]")
+    }
+}